@@ -2,42 +2,29 @@
 
 //! Kernel types.
 
+use crate::error::{to_result, Result};
 use crate::init::{self, PinInit};
 use alloc::boxed::Box;
 use core::{
     cell::UnsafeCell,
+    hash::{Hash, Hasher},
     marker::{PhantomData, PhantomPinned},
-    mem::MaybeUninit,
+    mem::{ManuallyDrop, MaybeUninit},
     ops::{Deref, DerefMut},
     ptr::NonNull,
     pin::Pin,
 };
 use crate::{
-    sync::{Arc, Ref, RefBorrow},
+    sync::{Arc, ArcBorrow, Ref, RefBorrow},
     c_types
 };
 
 extern "C" {
     fn rust_helper_hash_init(ht: *mut bindings::hlist_head, size: u32);
-    #[allow(dead_code)]
     fn rust_helper_rcu_read_lock();
-    #[allow(dead_code)]
     fn rust_helper_rcu_read_unlock();
 }
 
-extern "C" {
-    fn rust_helper_atomic_add(i: i32, v: *mut bindings::atomic_t);
-    fn rust_helper_atomic_sub(i: i32, v: *mut bindings::atomic_t);
-    fn rust_helper_atomic_sub_return(i: i32, v: *mut bindings::atomic_t) -> i32;
-    fn rust_helper_atomic_add_return(i: i32, v: *mut bindings::atomic_t) -> i32;
-    fn rust_helper_atomic_cmpxchg(v: *mut bindings::atomic_t, old: i32, new: i32) -> i32;
-    fn rust_helper_atomic_set(v: *mut bindings::atomic_t, i: i32);
-    fn rust_helper_atomic_inc(v: *mut bindings::atomic_t);
-    fn rust_helper_atomic_dec_and_test(v: *mut bindings::atomic_t) -> bool;
-    fn rust_helper_atomic_dec_return(v: *mut bindings::atomic_t) -> i32;
-    fn rust_helper_atomic_read(v: *mut bindings::atomic_t) -> i32;
-}
-
 /// Used to transfer ownership to and from foreign (non-Rust) languages.
 ///
 /// Ownership is transferred from Rust to a foreign language by calling [`Self::into_foreign`] and
@@ -172,6 +159,66 @@ impl ForeignOwnable for () {
     unsafe fn borrow_mut<'a>(_: *const core::ffi::c_void) -> Self::BorrowedMut<'a> {}
 }
 
+impl<T: 'static> ForeignOwnable for Arc<T> {
+    type Borrowed<'a> = ArcBorrow<'a, T>;
+    type BorrowedMut<'a> = ArcBorrow<'a, T>;
+
+    fn into_foreign(self) -> *const core::ffi::c_void {
+        Arc::into_raw(self) as _
+    }
+
+    unsafe fn from_foreign(ptr: *const core::ffi::c_void) -> Self {
+        // SAFETY: The safety requirements of this function ensure that `ptr` comes from a previous
+        // call to `Arc::into_raw`, so reconstructing the `Arc` here reclaims exactly the increment
+        // that was handed off.
+        unsafe { Arc::from_raw(ptr as _) }
+    }
+
+    unsafe fn borrow<'a>(ptr: *const core::ffi::c_void) -> ArcBorrow<'a, T> {
+        // SAFETY: The safety requirements of this method ensure that the underlying object remains
+        // alive for the duration of 'a, and this does not touch the refcount.
+        unsafe { ArcBorrow::from_raw(ptr as _) }
+    }
+
+    unsafe fn borrow_mut<'a>(ptr: *const core::ffi::c_void) -> ArcBorrow<'a, T> {
+        // An `&mut Arc<T>` only gives immutable access to the inner value (shared ownership may
+        // exist elsewhere), so this returns the same borrow type as `borrow`.
+        //
+        // SAFETY: Same as `borrow` above.
+        unsafe { Self::borrow(ptr) }
+    }
+}
+
+impl<T: 'static> ForeignOwnable for Ref<T> {
+    type Borrowed<'a> = RefBorrow<'a, T>;
+    type BorrowedMut<'a> = RefBorrow<'a, T>;
+
+    fn into_foreign(self) -> *const core::ffi::c_void {
+        Ref::into_usize(self) as _
+    }
+
+    unsafe fn from_foreign(ptr: *const core::ffi::c_void) -> Self {
+        // SAFETY: The safety requirements of this function ensure that `ptr` comes from a previous
+        // call to `Ref::into_usize`, so reconstructing the `Ref` here reclaims exactly the
+        // increment that was handed off.
+        unsafe { Ref::from_usize(ptr as _) }
+    }
+
+    unsafe fn borrow<'a>(ptr: *const core::ffi::c_void) -> RefBorrow<'a, T> {
+        // SAFETY: The safety requirements of this method ensure that the underlying object remains
+        // alive for the duration of 'a, and this does not touch the refcount.
+        unsafe { Ref::borrow_usize(ptr as _) }
+    }
+
+    unsafe fn borrow_mut<'a>(ptr: *const core::ffi::c_void) -> RefBorrow<'a, T> {
+        // An `&mut Ref<T>` only gives immutable access to the inner value, so this returns the
+        // same borrow type as `borrow`.
+        //
+        // SAFETY: Same as `borrow` above.
+        unsafe { Self::borrow(ptr) }
+    }
+}
+
 /// Runs a cleanup function/closure when dropped.
 ///
 /// The [`ScopeGuard::dismiss`] function prevents the cleanup function from running.
@@ -321,6 +368,18 @@ impl<T> Opaque<T> {
         }
     }
 
+    /// Creates a zero-filled value.
+    ///
+    /// This is a shorthand for C structs that document themselves as valid when zero-initialised,
+    /// saving callers from having to fall back to [`Self::ffi_init`] with a closure that just
+    /// `memset`s the slot.
+    pub const fn zeroed() -> Self {
+        Self {
+            value: UnsafeCell::new(MaybeUninit::zeroed()),
+            _pin: PhantomPinned,
+        }
+    }
+
     /// Creates a pin-initializer from the given initializer closure.
     ///
     /// The returned initializer calls the given closure with the pointer to the inner `T` of this
@@ -340,6 +399,24 @@ impl<T> Opaque<T> {
         }
     }
 
+    /// Creates a fallible pin-initializer from the given initializer closure.
+    ///
+    /// Like [`Self::ffi_init`], but for C `*_init()` functions that can themselves fail (e.g. by
+    /// returning a negative errno), letting the error propagate out of the pin-initializer instead
+    /// of being forced into `Infallible`.
+    ///
+    /// The returned initializer calls the given closure with the pointer to the inner `T` of this
+    /// `Opaque`. Since this memory is uninitialized, the closure is not allowed to read from it.
+    ///
+    /// This function is safe, because the `T` inside of an `Opaque` is allowed to be
+    /// uninitialized. Additionally, access to the inner `T` requires `unsafe`, so the caller needs
+    /// to verify at that point that the inner value is valid.
+    pub fn try_ffi_init<E>(init_func: impl FnOnce(*mut T) -> Result<(), E>) -> impl PinInit<Self, E> {
+        // SAFETY: We contain a `MaybeUninit`, so it is OK for the `init_func` to not fully
+        // initialize the `T` when it returns an error and aborts initialization.
+        unsafe { init::pin_init_from_closure(move |slot| init_func(Self::raw_get(slot))) }
+    }
+
     /// Returns a raw pointer to the opaque data.
     pub fn get(&self) -> *mut T {
         UnsafeCell::get(&self.value).cast::<T>()
@@ -472,6 +549,41 @@ impl<T: AlwaysRefCounted> Drop for ARef<T> {
     }
 }
 
+impl<T: 'static + AlwaysRefCounted> ForeignOwnable for ARef<T> {
+    type Borrowed<'a> = &'a T;
+    type BorrowedMut<'a> = &'a T;
+
+    fn into_foreign(self) -> *const core::ffi::c_void {
+        let ptr = self.ptr;
+        // Hand the single owned increment to the foreign side; it must come back through
+        // `from_foreign` exactly once so `dec_ref` runs exactly once.
+        core::mem::forget(self);
+        ptr.as_ptr() as _
+    }
+
+    unsafe fn from_foreign(ptr: *const core::ffi::c_void) -> Self {
+        // SAFETY: The safety requirements of this function ensure that `ptr` comes from a
+        // previous call to `into_foreign`, which forgot an `ARef` that owned exactly one
+        // increment on the refcount; reconstructing it here hands that increment back so `drop`
+        // releases it exactly once.
+        unsafe { Self::from_raw(NonNull::new_unchecked(ptr as _)) }
+    }
+
+    unsafe fn borrow<'a>(ptr: *const core::ffi::c_void) -> &'a T {
+        // SAFETY: The safety requirements of this method ensure that the object remains alive and
+        // immutable for the duration of 'a, and this does not touch the refcount.
+        unsafe { &*ptr.cast() }
+    }
+
+    unsafe fn borrow_mut<'a>(ptr: *const core::ffi::c_void) -> &'a T {
+        // `&mut ARef<T>` only gives immutable access to the inner value, so this returns the same
+        // borrow type as `borrow`.
+        //
+        // SAFETY: Same as `borrow` above.
+        unsafe { Self::borrow(ptr) }
+    }
+}
+
 /// A sum type that always holds either a value of type `L` or `R`.
 pub enum Either<L, R> {
     /// Constructs an instance of [`Either`] containing a value of type `L`.
@@ -534,11 +646,122 @@ impl HlistHead {
     }
 }
 
+/// A proof that the current thread is inside an RCU read-side critical section.
+///
+/// Modeled on [`ScopeGuard`]: constructing one calls `rcu_read_lock()`, and the matching
+/// `rcu_read_unlock()` runs when it is dropped. [`Hashtable::for_each_possible_rcu`] requires a
+/// `&RcuReadGuard`, so the bucket lookup itself can only happen inside a critical section. The raw
+/// bucket head it returns, and the nodes reached from it, carry no lifetime: the borrow checker
+/// does not stop them from being used after the guard is dropped.
+///
+/// # Invariants
+///
+/// While a `RcuReadGuard` exists on a thread, that thread is inside an RCU read-side critical
+/// section.
+pub struct RcuReadGuard {
+    _not_send_sync: NotThreadSafe,
+}
+
+impl RcuReadGuard {
+    /// Enters an RCU read-side critical section.
+    pub fn new() -> Self {
+        // SAFETY: It is always safe to call `rcu_read_lock()`.
+        unsafe { rust_helper_rcu_read_lock() };
+        // INVARIANT: We just entered the critical section that `drop` will leave.
+        Self {
+            _not_send_sync: NotThreadSafe,
+        }
+    }
+}
+
+impl Drop for RcuReadGuard {
+    fn drop(&mut self) {
+        // SAFETY: By the type invariants, we are currently inside the read-side critical section
+        // that this call leaves.
+        unsafe { rust_helper_rcu_read_unlock() };
+    }
+}
+
+/// Loads a pointer that may be concurrently published by [`rust_helper_rcu_assign_pointer`]-style
+/// updates, pairing with the publishing side's release barrier.
+///
+/// This is the Rust analogue of the C `rcu_dereference()` macro: a `READ_ONCE`-style volatile load
+/// followed by the address-dependency barrier (a no-op on architectures, like x86, where
+/// data-dependent loads are already ordered).
+///
+/// # Safety
+///
+/// `p` must be a valid pointer to a `*mut T` that is only ever updated via `rcu_assign_pointer`
+/// (or equivalent), and the caller must be inside an RCU read-side critical section for the
+/// entire lifetime of the value being dereferenced through the result.
+pub unsafe fn rcu_dereference<T>(p: *const *mut T) -> *mut T {
+    extern "C" {
+        fn rust_helper_smp_read_barrier_depends();
+    }
+    // SAFETY: `p` is valid per the caller's safety requirements. The volatile read matches
+    // `READ_ONCE`, and `smp_read_barrier_depends` is the dependency barrier `rcu_dereference`
+    // pairs with `rcu_assign_pointer`'s release barrier.
+    unsafe {
+        let v = core::ptr::read_volatile(p);
+        rust_helper_smp_read_barrier_depends();
+        v
+    }
+}
+
 /// The `hash_init` function is a wrapper around the `rust_helper_hash_init` function from the kernel bindings. It initializes a hash table with the given size. The `ht` parameter is a pointer to the hash table to initialize.
 pub fn hash_init(ht: *mut bindings::hlist_head, size: u32) {
     unsafe { rust_helper_hash_init(ht, size) };
 }
 
+/// Hashes `key` down to a 32-bit value usable as a raw `Hashtable` key.
+///
+/// Uses a simple FNV-1a hasher; combined with [`hash_min`], it guarantees that two equal keys
+/// always land in the same bucket of a given `Hashtable<N>` (the bucket index depends on `N`).
+fn hash_key<K: Hash>(key: &K) -> u32 {
+    struct FnvHasher(u64);
+
+    impl Hasher for FnvHasher {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            for &b in bytes {
+                self.0 = (self.0 ^ b as u64).wrapping_mul(0x100_0000_01b3);
+            }
+        }
+    }
+
+    let mut hasher = FnvHasher(0xcbf2_9ce4_8422_2325);
+    key.hash(&mut hasher);
+    hasher.finish() as u32
+}
+
+/// Folds a 32-bit hash down to a bucket index for an `N`-bucket table.
+///
+/// Mirrors the C `hash_min()`/`hash_32()` helpers: multiply by the 32-bit golden ratio
+/// (`0x61C88647`) and keep the top `log2(N)` bits, so Rust-computed buckets always agree with
+/// `hash_add`'s C-side reduction.
+///
+/// # Panics
+///
+/// Panics if `N` is not a power of two, since `hash_add`'s bucket reduction (and `Hashtable<N>`
+/// itself) are only meaningful for a power-of-two bucket count.
+fn hash_min<const N: usize>(hash: u32) -> u32 {
+    assert!(
+        N.is_power_of_two(),
+        "Hashtable<N> requires N to be a power of two"
+    );
+    let bits = N.trailing_zeros();
+    if bits == 0 {
+        // A single-bucket table: every key folds to bucket 0, and shifting by a full `u32::BITS`
+        // would otherwise overflow.
+        0
+    } else {
+        hash.wrapping_mul(0x61C8_8647) >> (u32::BITS - bits)
+    }
+}
+
 /// A list to store structs needed to hash.
 pub struct Hashtable<const N: usize> {
     table: [bindings::hlist_head; N],
@@ -557,8 +780,16 @@ impl<const N: usize> Hashtable<N> {
         Self { table: table }
     }
 
-    /// Add a new struct to Hashtable.
-    pub fn add(&mut self, node: &mut bindings::hlist_node, key: u32) {
+    /// Add a new struct to Hashtable, keyed on any `K: Hash`.
+    ///
+    /// Hashes `key` and folds it into a bucket index the same way [`Self::head`] does for
+    /// lookups, so insert and lookup always agree on the bucket.
+    pub fn add<K: Hash>(&mut self, node: &mut bindings::hlist_node, key: &K) {
+        self.add_raw(node, hash_min::<N>(hash_key(key)));
+    }
+
+    /// Add a new struct to Hashtable under a precomputed raw bucket key.
+    pub fn add_raw(&mut self, node: &mut bindings::hlist_node, key: u32) {
         extern "C" {
             fn rust_helper_hash_add(
                 ht: *mut bindings::hlist_head,
@@ -587,8 +818,37 @@ impl<const N: usize> Hashtable<N> {
         }
     }
 
-    /// Get the bucket's head which is indexed by key.
-    pub fn head(&mut self, key: u32) -> *const bindings::hlist_head {
+    /// Get the bucket's head which is indexed by any `K: Hash`.
+    ///
+    /// Uses the same hash-and-fold as [`Self::add`], so a key that was added is always found in
+    /// the bucket returned here.
+    pub fn head<K: Hash>(&mut self, key: &K) -> *const bindings::hlist_head {
+        self.head_raw(hash_min::<N>(hash_key(key)))
+    }
+
+    /// Get the bucket's head which is indexed by a precomputed raw key.
+    pub fn head_raw(&mut self, key: u32) -> *const bindings::hlist_head {
+        extern "C" {
+            fn rust_helper_get_hlist_head(
+                ht: *const bindings::hlist_head,
+                length: usize,
+                key: u32,
+            ) -> *const bindings::hlist_head;
+        }
+        unsafe { rust_helper_get_hlist_head(&self.table as *const bindings::hlist_head, N, key) }
+    }
+
+    /// Returns the bucket's head for `key`, to be walked with [`hash_for_each_possible_rcu!`].
+    ///
+    /// Takes `guard` purely as a proof that the caller is inside an RCU read-side critical
+    /// section; the traversal itself still goes through [`hash_for_each_possible_rcu!`] so that
+    /// every `next` pointer is loaded with [`rcu_dereference`] rather than a plain read.
+    pub fn for_each_possible_rcu(
+        &self,
+        guard: &RcuReadGuard,
+        key: u32,
+    ) -> *const bindings::hlist_head {
+        let _ = guard;
         extern "C" {
             fn rust_helper_get_hlist_head(
                 ht: *const bindings::hlist_head,
@@ -641,157 +901,522 @@ macro_rules! hash_for_each_possible {
     };
 }
 
-/// Used to convert an object into a raw pointer that represents it.
+/// Iterate all non-null structs beginning with a bucket head obtained under RCU protection.
 ///
-/// It can eventually be converted back into the object. This is used to store objects as pointers
-/// in kernel data structures, for example, an implementation of [`FileOperations`] in `struct
-/// file::private_data`.
-pub trait PointerWrapper {
-    /// Type of values borrowed between calls to [`PointerWrapper::into_pointer`] and
-    /// [`PointerWrapper::from_pointer`].
-    type Borrowed: Deref;
+/// Like [`hash_for_each_possible!`], but every `first`/`next` pointer is loaded through
+/// [`rcu_dereference`] instead of a plain `*` read, so the walk is safe against a concurrent
+/// insert/remove. `$head` must come from [`Hashtable::for_each_possible_rcu`], whose `&RcuReadGuard`
+/// argument proves the whole traversal happens inside a read-side critical section; the guard must
+/// outlive every `$pos` yielded here.
+#[macro_export]
+macro_rules! hash_for_each_possible_rcu {
+    ($pos:ident, $head:expr, $type:ty, $member:ident, { $($block:tt)* } ) => {
+        let mut $pos = $crate::hlist_entry_safe!(
+            unsafe { $crate::types::rcu_dereference(&(*$head).first as *const _ as *const *mut _) },
+            $type,
+            $member
+        );
+        while (!$pos.is_null()) {
+            $($block)*
+            $pos = $crate::hlist_entry_safe!(
+                unsafe {
+                    $crate::types::rcu_dereference(
+                        &(*$pos).$member.0.next as *const _ as *const *mut _
+                    )
+                },
+                $type,
+                $member
+            );
+        }
+    };
+}
 
-    /// Returns the raw pointer.
-    fn into_pointer(self) -> *const c_types::c_void;
+impl<T: ForeignOwnable + Deref> ForeignOwnable for Pin<T> {
+    type Borrowed<'a> = T::Borrowed<'a>;
+    type BorrowedMut<'a> = T::Borrowed<'a>;
 
-    /// Returns a borrowed value.
-    ///
-    /// # Safety
-    ///
-    /// `ptr` must have been returned by a previous call to [`PointerWrapper::into_pointer`].
-    /// Additionally, [`PointerWrapper::from_pointer`] can only be called after *all* values
-    /// returned by [`PointerWrapper::borrow`] have been dropped.
-    unsafe fn borrow(ptr: *const c_types::c_void) -> Self::Borrowed;
+    fn into_foreign(self) -> *const core::ffi::c_void {
+        // SAFETY: We continue to treat the pointer as pinned by returning just a pointer to it to
+        // the caller.
+        let inner = unsafe { Pin::into_inner_unchecked(self) };
+        inner.into_foreign()
+    }
 
-    /// Returns the instance back from the raw pointer.
-    ///
-    /// # Safety
-    ///
-    /// The passed pointer must come from a previous call to [`PointerWrapper::into_pointer()`].
-    unsafe fn from_pointer(ptr: *const c_types::c_void) -> Self;
+    unsafe fn from_foreign(ptr: *const core::ffi::c_void) -> Self {
+        // SAFETY: The object was originally pinned.
+        // The passed pointer comes from a previous call to `T::into_foreign()`.
+        unsafe { Pin::new_unchecked(T::from_foreign(ptr)) }
+    }
+
+    unsafe fn borrow<'a>(ptr: *const core::ffi::c_void) -> Self::Borrowed<'a> {
+        // SAFETY: The safety requirements for this function are the same as the ones for
+        // `T::borrow`.
+        unsafe { T::borrow(ptr) }
+    }
+
+    unsafe fn borrow_mut<'a>(ptr: *const core::ffi::c_void) -> Self::BorrowedMut<'a> {
+        // A `Pin<T>` behind a foreign pointer cannot be moved out of, so mutable access is
+        // restricted the same way `T::borrow` already restricts it.
+        //
+        // SAFETY: Same as `borrow` above.
+        unsafe { Self::borrow(ptr) }
+    }
 }
 
-impl<T> PointerWrapper for Box<T> {
-    type Borrowed = UnsafeReference<T>;
+/// Tracks, for a single list `ID`, whether a [`ListArc`] currently exists for an object.
+///
+/// Embed one `AtomicTracker<ID>` field per list a `T` can be linked into. [`ListArc::try_from_arc`]
+/// claims the tracker with a single acquire [`Atomic::compare_exchange`], so at most one
+/// `ListArc<T, ID>` can exist for a given object at a time; dropping the `ListArc` releases it
+/// with a matching release store.
+pub struct AtomicTracker<const ID: u64 = 0> {
+    inner: Atomic<i32>,
+}
 
-    fn into_pointer(self) -> *const c_types::c_void {
-        Box::into_raw(self) as _
+impl<const ID: u64> AtomicTracker<ID> {
+    /// Constructs a new tracker with no outstanding [`ListArc`].
+    pub fn new() -> Self {
+        Self {
+            inner: Atomic::new(0),
+        }
     }
 
-    unsafe fn borrow(ptr: *const c_types::c_void) -> Self::Borrowed {
-        // SAFETY: The safety requirements for this function ensure that the object is still alive,
-        // so it is safe to dereference the raw pointer.
-        // The safety requirements also ensure that the object remains alive for the lifetime of
-        // the returned value.
-        unsafe { UnsafeReference::new(&*ptr.cast()) }
+    fn try_claim(&self) -> bool {
+        // Acquire so that the claimant synchronizes-with the release in `release` below, seeing
+        // every write the previous `ListArc` holder made before giving it up.
+        self.inner.compare_exchange(0, 1, Ordering::Acquire).is_ok()
     }
 
-    unsafe fn from_pointer(ptr: *const c_types::c_void) -> Self {
-        // SAFETY: The passed pointer comes from a previous call to [`Self::into_pointer()`].
-        unsafe { Box::from_raw(ptr as _) }
+    fn release(&self) {
+        // Release so that a subsequent `try_claim` synchronizes-with this store.
+        self.inner.store(0, Ordering::Release);
     }
 }
 
-impl<T> PointerWrapper for Ref<T> {
-    type Borrowed = RefBorrow<T>;
+/// Types with an [`AtomicTracker<ID>`] that lets them hand out a unique [`ListArc<Self, ID>`].
+pub trait ListArcSafe<const ID: u64 = 0> {
+    /// Returns this object's [`ListArc`] uniqueness tracker for list `ID`.
+    fn tracker(&self) -> &AtomicTracker<ID>;
+}
 
-    fn into_pointer(self) -> *const c_types::c_void {
-        Ref::into_usize(self) as _
-    }
+/// A reference-counted pointer that is statically known to be the only [`ListArc`] for list `ID`
+/// over its pointee, which is what makes it safe to insert into that [`List`].
+///
+/// # Invariants
+///
+/// While a `ListArc<T, ID>` exists, the pointee's [`AtomicTracker<ID>`] is claimed, and no other
+/// `ListArc<T, ID>` over the same object exists.
+pub struct ListArc<T: ListArcSafe<ID>, const ID: u64 = 0> {
+    arc: Arc<T>,
+}
 
-    unsafe fn borrow(ptr: *const c_types::c_void) -> Self::Borrowed {
-        // SAFETY: The safety requirements for this function ensure that the underlying object
-        // remains valid for the lifetime of the returned value.
-        unsafe { Ref::borrow_usize(ptr as _) }
+impl<T: ListArcSafe<ID>, const ID: u64> ListArc<T, ID> {
+    /// Tries to claim the unique [`ListArc`] token for `arc`.
+    ///
+    /// Returns the `Arc` back in `Err` if a `ListArc<T, ID>` already exists for this object.
+    pub fn try_from_arc(arc: Arc<T>) -> Result<Self, Arc<T>> {
+        if arc.tracker().try_claim() {
+            Ok(Self { arc })
+        } else {
+            Err(arc)
+        }
     }
 
-    unsafe fn from_pointer(ptr: *const c_types::c_void) -> Self {
-        // SAFETY: The passed pointer comes from a previous call to [`Self::into_pointer()`].
-        unsafe { Ref::from_usize(ptr as _) }
+    /// Consumes the [`ListArc`], releasing its uniqueness claim and returning the plain [`Arc`].
+    pub fn into_arc(self) -> Arc<T> {
+        let this = ManuallyDrop::new(self);
+        this.arc.tracker().release();
+        // SAFETY: `this` is never used again and its `Drop` (which would release the tracker a
+        // second time) does not run, so this is the only place the claim is released.
+        unsafe { core::ptr::read(&this.arc) }
     }
 }
 
-impl<T> PointerWrapper for Arc<T> {
-    type Borrowed = UnsafeReference<T>;
+impl<T: ListArcSafe<ID>, const ID: u64> Deref for ListArc<T, ID> {
+    type Target = T;
 
-    fn into_pointer(self) -> *const c_types::c_void {
-        Arc::into_raw(self) as _
+    fn deref(&self) -> &T {
+        &self.arc
     }
+}
 
-    unsafe fn borrow(ptr: *const c_types::c_void) -> Self::Borrowed {
-        // SAFETY: The safety requirements for this function ensure that the object is still alive,
-        // so it is safe to dereference the raw pointer.
-        // The safety requirements also ensure that the object remains alive for the lifetime of
-        // the returned value.
-        unsafe { UnsafeReference::new(&*ptr.cast()) }
+impl<T: ListArcSafe<ID>, const ID: u64> Drop for ListArc<T, ID> {
+    fn drop(&mut self) {
+        self.arc.tracker().release();
     }
+}
 
-    unsafe fn from_pointer(ptr: *const c_types::c_void) -> Self {
-        // SAFETY: The passed pointer comes from a previous call to [`Self::into_pointer()`].
-        unsafe { Arc::from_raw(ptr as _) }
-    }
+struct ListLinksFields {
+    next: Option<NonNull<ListLinksFields>>,
+    prev: Option<NonNull<ListLinksFields>>,
 }
 
-/// A reference with manually-managed lifetime.
+/// Intrusive prev/next links embedded in a `T` that can be a member of a [`List<T, ID>`].
 ///
 /// # Invariants
 ///
-/// There are no mutable references to the underlying object, and it remains valid for the lifetime
-/// of the [`UnsafeReference`] instance.
-pub struct UnsafeReference<T: ?Sized> {
-    ptr: NonNull<T>,
+/// The links are either both `None` (the object is not currently in a list `ID`), or both
+/// `Some`, pointing at adjacent members of the circular doubly-linked list owned by a [`List`].
+pub struct ListLinks<const ID: u64 = 0> {
+    inner: Opaque<ListLinksFields>,
 }
 
-impl<T: ?Sized> UnsafeReference<T> {
-    /// Creates a new [`UnsafeReference`] instance.
+impl<const ID: u64> ListLinks<ID> {
+    /// Constructs a new, unlinked, [`ListLinks`].
+    pub const fn new() -> Self {
+        Self {
+            inner: Opaque::new(ListLinksFields {
+                next: None,
+                prev: None,
+            }),
+        }
+    }
+}
+
+/// Types with an embedded [`ListLinks<ID>`] field that makes them usable with [`List<T, ID>`].
+///
+/// # Safety
+///
+/// Implementers must ensure [`Self::view_links`] and [`Self::view_value`] are exact inverses of
+/// each other (locating the same [`ListLinks<ID>`] field of the same `Self`), and that this
+/// field's address is stable for as long as `self` is reachable through a [`ListArc<Self, ID>`].
+pub unsafe trait ListItem<const ID: u64 = 0>: ListArcSafe<ID> {
+    /// Returns a raw pointer to this object's intrusive links for list `ID`.
     ///
     /// # Safety
     ///
-    /// Callers must ensure the following for the lifetime of the returned [`UnsafeReference`]
-    /// instance:
-    /// 1. That `obj` remains valid;
-    /// 2. That no mutable references to `obj` are created.
-    unsafe fn new(obj: &T) -> Self {
-        // INVARIANT: The safety requirements of this function ensure that the invariants hold.
+    /// `me` must point at a valid `Self`.
+    unsafe fn view_links(me: *const Self) -> *mut ListLinks<ID>;
+
+    /// Recovers a raw pointer to the `Self` that embeds `links`.
+    ///
+    /// # Safety
+    ///
+    /// `links` must have been produced by a previous call to [`Self::view_links`].
+    unsafe fn view_value(links: *mut ListLinks<ID>) -> *mut Self;
+}
+
+/// An intrusive doubly-linked list of [`ListArc<T, ID>`].
+///
+/// Unlike an ordinary linked list, `List` does not allocate a node per element: the prev/next
+/// pointers live inside `T` itself, in its [`ListLinks<ID>`], so [`Self::push_back`] and
+/// [`Self::push_front`] never fail.
+///
+/// # Examples
+///
+/// ```
+/// # use kernel::sync::Arc;
+/// # use kernel::types::{AtomicTracker, List, ListArc, ListArcSafe, ListItem, ListLinks};
+/// struct Example {
+///     value: u32,
+///     tracker: AtomicTracker,
+///     links: ListLinks,
+/// }
+///
+/// impl ListArcSafe for Example {
+///     fn tracker(&self) -> &AtomicTracker {
+///         &self.tracker
+///     }
+/// }
+///
+/// // SAFETY: `view_links` and `view_value` both locate `Example::links`, and it never moves out
+/// // from under a `ListArc<Example>`.
+/// unsafe impl ListItem for Example {
+///     unsafe fn view_links(me: *const Self) -> *mut ListLinks {
+///         // SAFETY: `me` is a valid `Example`, per this function's safety requirements.
+///         unsafe { core::ptr::addr_of!((*me).links) as *mut ListLinks }
+///     }
+///
+///     unsafe fn view_value(links: *mut ListLinks) -> *mut Self {
+///         // SAFETY: `links` was produced by `view_links` above, which points at `Example::links`.
+///         unsafe { kernel::container_of!(links, Self, links) }
+///     }
+/// }
+///
+/// let a = ListArc::try_from_arc(Arc::try_new(Example {
+///     value: 1,
+///     tracker: AtomicTracker::new(),
+///     links: ListLinks::new(),
+/// })?)
+/// .unwrap();
+/// let b = ListArc::try_from_arc(Arc::try_new(Example {
+///     value: 2,
+///     tracker: AtomicTracker::new(),
+///     links: ListLinks::new(),
+/// })?)
+/// .unwrap();
+///
+/// let mut list = List::new();
+/// list.push_back(a);
+/// list.push_back(b);
+///
+/// assert_eq!(list.iter().map(|e| e.value).collect::<Vec<_>>(), [1, 2]);
+///
+/// let front = list.pop_front().unwrap();
+/// assert_eq!(front.value, 1);
+///
+/// // Releasing the uniqueness claim hands back a plain, shareable `Arc`.
+/// let shared = front.into_arc();
+/// assert_eq!(shared.value, 1);
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// # Invariants
+///
+/// Every [`ListLinksFields`] reachable from `head` belongs to a `T` owned by a `ListArc<T, ID>`
+/// that this list holds (without running its `Drop`), and the links form a circular
+/// doubly-linked list.
+pub struct List<T: ListItem<ID>, const ID: u64 = 0> {
+    head: Option<NonNull<ListLinksFields>>,
+    _p: PhantomData<ListArc<T, ID>>,
+}
+
+// SAFETY: The list owns its `ListArc<T, ID>` elements, so it can be sent to another thread as
+// long as `T` can.
+unsafe impl<T: ListItem<ID> + Send, const ID: u64> Send for List<T, ID> {}
+// SAFETY: `&List` only lets callers observe shared references into the list's elements.
+unsafe impl<T: ListItem<ID> + Sync, const ID: u64> Sync for List<T, ID> {}
+
+impl<T: ListItem<ID>, const ID: u64> List<T, ID> {
+    /// Constructs a new, empty, list.
+    pub const fn new() -> Self {
         Self {
-            ptr: NonNull::from(obj),
+            head: None,
+            _p: PhantomData,
+        }
+    }
+
+    /// Returns whether the list has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    fn links_of(item: &T) -> NonNull<ListLinksFields> {
+        // SAFETY: `item` is a valid `&T`.
+        let links = unsafe { T::view_links(item) };
+        // SAFETY: `links` points at the live `ListLinks<ID>` embedded in `item`; forming a raw
+        // pointer to its inner field needs no intermediate reference.
+        let fields = unsafe { Opaque::raw_get(core::ptr::addr_of!((*links).inner)) };
+        // SAFETY: `fields` is derived from the non-null `links`.
+        unsafe { NonNull::new_unchecked(fields) }
+    }
+
+    /// Inserts `item` at the back of the list.
+    pub fn push_back(&mut self, item: ListArc<T, ID>) {
+        let item = ManuallyDrop::new(item);
+        let node = Self::links_of(&item);
+        // SAFETY: `node` was just obtained from an object that is not currently linked (it is not
+        // yet reachable from any list), and the list's invariants let us mutate the links of
+        // members it owns.
+        unsafe {
+            match self.head {
+                None => {
+                    (*node.as_ptr()).next = Some(node);
+                    (*node.as_ptr()).prev = Some(node);
+                    self.head = Some(node);
+                }
+                Some(head) => {
+                    let tail = (*head.as_ptr()).prev.unwrap();
+                    (*tail.as_ptr()).next = Some(node);
+                    (*node.as_ptr()).prev = Some(tail);
+                    (*node.as_ptr()).next = Some(head);
+                    (*head.as_ptr()).prev = Some(node);
+                }
+            }
+        }
+    }
+
+    /// Inserts `item` at the front of the list.
+    pub fn push_front(&mut self, item: ListArc<T, ID>) {
+        self.push_back(item);
+        // SAFETY: The list is non-empty because `push_back` just inserted a node into it.
+        self.head = unsafe { (*self.head.unwrap().as_ptr()).prev };
+    }
+
+    /// Removes and returns the element at the front of the list, if any.
+    pub fn pop_front(&mut self) -> Option<ListArc<T, ID>> {
+        let head = self.head?;
+        // SAFETY: `head` is a valid member of this list by the type invariants.
+        unsafe { Some(self.remove_node(head)) }
+    }
+
+    /// Removes `item` from the list and returns it.
+    ///
+    /// Returns `None` if `item` is not currently linked into this list.
+    pub fn remove(&mut self, item: &T) -> Option<ListArc<T, ID>> {
+        let node = Self::links_of(item);
+        let head = self.head?;
+        let mut cur = head;
+        while cur != node {
+            // SAFETY: `cur` is a member of this list, so its links are valid and set.
+            cur = unsafe { (*cur.as_ptr()).next.unwrap() };
+            if cur == head {
+                return None;
+            }
+        }
+        // SAFETY: The walk above found `node` among this list's members.
+        Some(unsafe { self.remove_node(node) })
+    }
+
+    /// # Safety
+    ///
+    /// `node` must be a currently-linked member of this list.
+    unsafe fn remove_node(&mut self, node: NonNull<ListLinksFields>) -> ListArc<T, ID> {
+        // SAFETY: `node` is linked into this list, so its neighbours and the arc it came from are
+        // all still valid.
+        unsafe {
+            let next = (*node.as_ptr()).next.unwrap();
+            let prev = (*node.as_ptr()).prev.unwrap();
+            if next == node {
+                self.head = None;
+            } else {
+                (*next.as_ptr()).prev = Some(prev);
+                (*prev.as_ptr()).next = Some(next);
+                if self.head == Some(node) {
+                    self.head = Some(next);
+                }
+            }
+            (*node.as_ptr()).next = None;
+            (*node.as_ptr()).prev = None;
+
+            // SAFETY: `node` is the `inner` field of a `ListLinks<ID>` produced by a previous
+            // `T::view_links` call, per this list's invariants.
+            let links: *mut ListLinks<ID> = crate::container_of!(node.as_ptr(), ListLinks<ID>, inner);
+            let item = T::view_value(links);
+            ListArc {
+                arc: Arc::from_raw(item),
+            }
+        }
+    }
+
+    /// Returns an iterator over shared references to the list's elements, front to back.
+    pub fn iter(&self) -> Iter<'_, T, ID> {
+        Iter {
+            next: self.head,
+            stop: self.head,
+            first: true,
+            _p: PhantomData,
+        }
+    }
+
+    /// Returns a cursor positioned at the front of the list.
+    pub fn cursor_front(&mut self) -> Cursor<'_, T, ID> {
+        Cursor {
+            current: self.head,
+            list: self,
         }
     }
 }
 
-impl<T: ?Sized> Deref for UnsafeReference<T> {
-    type Target = T;
+/// An iterator over shared references to the elements of a [`List<T, ID>`].
+pub struct Iter<'a, T: ListItem<ID>, const ID: u64 = 0> {
+    next: Option<NonNull<ListLinksFields>>,
+    stop: Option<NonNull<ListLinksFields>>,
+    first: bool,
+    _p: PhantomData<&'a List<T, ID>>,
+}
 
-    fn deref(&self) -> &Self::Target {
-        // SAFETY: By the type invariant, the object is still valid and alive, and there are no
-        // mutable references to it.
-        unsafe { self.ptr.as_ref() }
+impl<'a, T: ListItem<ID>, const ID: u64> Iterator for Iter<'a, T, ID> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let node = self.next?;
+        if !self.first && Some(node) == self.stop {
+            return None;
+        }
+        self.first = false;
+        // SAFETY: `node` is a live member of the list that outlives `'a`, since the list is
+        // borrowed for `'a`.
+        self.next = unsafe { (*node.as_ptr()).next };
+        // SAFETY: `node` is the `inner` field of a `ListLinks<ID>` produced by a previous
+        // `T::view_links` call, so recovering the containing `T` and reborrowing it immutably is
+        // sound for the duration of `'a`.
+        Some(unsafe {
+            let links: *mut ListLinks<ID> = crate::container_of!(node.as_ptr(), ListLinks<ID>, inner);
+            &*T::view_value(links)
+        })
     }
 }
 
-impl<T: PointerWrapper + Deref> PointerWrapper for Pin<T> {
-    type Borrowed = T::Borrowed;
+/// A cursor into a [`List<T, ID>`] that can remove the element it currently points at.
+pub struct Cursor<'a, T: ListItem<ID>, const ID: u64 = 0> {
+    current: Option<NonNull<ListLinksFields>>,
+    list: &'a mut List<T, ID>,
+}
 
-    fn into_pointer(self) -> *const c_types::c_void {
-        // SAFETY: We continue to treat the pointer as pinned by returning just a pointer to it to
-        // the caller.
-        let inner = unsafe { Pin::into_inner_unchecked(self) };
-        inner.into_pointer()
+impl<'a, T: ListItem<ID>, const ID: u64> Cursor<'a, T, ID> {
+    /// Returns a shared reference to the element the cursor currently points at.
+    pub fn current(&self) -> Option<&T> {
+        let node = self.current?;
+        // SAFETY: `node` is a live member of `self.list`.
+        Some(unsafe {
+            let links: *mut ListLinks<ID> = crate::container_of!(node.as_ptr(), ListLinks<ID>, inner);
+            &*T::view_value(links)
+        })
     }
 
-    unsafe fn borrow(ptr: *const c_types::c_void) -> Self::Borrowed {
-        // SAFETY: The safety requirements for this function are the same as the ones for
-        // `T::borrow`.
-        unsafe { T::borrow(ptr) }
+    /// Advances the cursor to the next element.
+    pub fn move_next(&mut self) {
+        if let Some(node) = self.current {
+            let next = unsafe { (*node.as_ptr()).next };
+            self.current = if next == self.list.head { None } else { next };
+        }
     }
 
-    unsafe fn from_pointer(p: *const c_types::c_void) -> Self {
-        // SAFETY: The object was originally pinned.
-        // The passed pointer comes from a previous call to `inner::into_pointer()`.
-        unsafe { Pin::new_unchecked(T::from_pointer(p)) }
+    /// Removes the element the cursor currently points at, advancing the cursor to what follows
+    /// it.
+    pub fn remove_current(&mut self) -> Option<ListArc<T, ID>> {
+        let node = self.current?;
+        // SAFETY: `node` is a currently-linked member of `self.list`.
+        let next = unsafe { (*node.as_ptr()).next };
+        // The end-of-list check must match `move_next`'s: capture `head` before `remove_node`
+        // below updates it, since removing the tail also wraps `next` back to `head`.
+        let head = self.list.head;
+        self.current = if next == head { None } else { next };
+        // SAFETY: `node` is a currently-linked member of `self.list`.
+        Some(unsafe { self.list.remove_node(node) })
     }
 }
 
+/// A field of `T` that is only mutably accessible while the caller holds the unique
+/// `ListArc<T, ID>` for `T`, even though `T` itself may be shared via a plain [`Arc`].
+pub struct ListArcField<U, const ID: u64 = 0> {
+    value: UnsafeCell<U>,
+}
+
+impl<U, const ID: u64> ListArcField<U, ID> {
+    /// Constructs a new [`ListArcField`] wrapping `value`.
+    pub const fn new(value: U) -> Self {
+        Self {
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// Gets shared access to the field; always available, like any other field behind `&T`.
+    pub fn access<'a, T: ListArcSafe<ID>>(&'a self, _owner: &'a T) -> &'a U {
+        // SAFETY: Shared access to `owner` implies shared access to its fields.
+        unsafe { &*self.value.get() }
+    }
+
+    /// Gets mutable access to the field, proven safe by the caller holding the unique
+    /// [`ListArc<T, ID>`] for the object this field lives in.
+    pub fn access_mut<'a, T: ListArcSafe<ID>>(
+        &'a self,
+        _owner: &'a mut ListArc<T, ID>,
+    ) -> &'a mut U {
+        // SAFETY: `_owner` is an exclusive borrow of the unique `ListArc<T, ID>` for this object,
+        // so at most one `&mut U` can be minted from it at a time.
+        unsafe { &mut *self.value.get() }
+    }
+}
+
+// SAFETY: Access to the inner `U` is only handed out while the appropriate uniqueness proof (a
+// `&mut ListArc<T, ID>` for `access_mut`) is held, so it is safe to share a `ListArcField` across
+// threads as long as `U` can be.
+unsafe impl<U: Send, const ID: u64> Send for ListArcField<U, ID> {}
+// SAFETY: See above; `&ListArcField` alone never exposes mutable access.
+unsafe impl<U: Send + Sync, const ID: u64> Sync for ListArcField<U, ID> {}
+
 /// The `RcuHead` struct is a wrapper around the `bindings::callback_head` struct from the kernel bindings. It represents a node in a Read-Copy-Update (RCU) list.
 pub struct RcuHead(bindings::callback_head);
 
@@ -802,11 +1427,160 @@ impl RcuHead {
     }
 }
 
+extern "C" {
+    fn rust_helper_rcu_assign_pointer(p: *mut *mut core::ffi::c_void, v: *mut core::ffi::c_void);
+    fn rust_helper_call_rcu(
+        head: *mut bindings::callback_head,
+        func: unsafe extern "C" fn(*mut bindings::callback_head),
+    );
+}
+
+/// A box pairing a deferred-reclaim `callback_head` with the foreign pointer it will reclaim once the
+/// grace period ends.
+struct RcuBox<P> {
+    head: bindings::callback_head,
+    ptr: *const core::ffi::c_void,
+    _p: PhantomData<P>,
+}
+
+/// The `call_rcu` trampoline: recovers the [`RcuBox`] from the `callback_head` offset and drops
+/// the `P` it was keeping alive.
+///
+/// # Safety
+///
+/// `head` must be the `head` field of an `RcuBox<P>` that was `Box::into_raw`'d into
+/// [`Rcu::replace`] and not yet reclaimed.
+unsafe extern "C" fn rcu_reclaim<P: ForeignOwnable>(head: *mut bindings::callback_head) {
+    // SAFETY: Per this function's safety requirements, `head` came from `Box::into_raw` on an
+    // `RcuBox<P>`, and the grace period has now elapsed, so no reader can still be dereferencing
+    // the old pointer.
+    let this = unsafe { Box::from_raw(crate::container_of!(head, RcuBox<P>, head) as *mut RcuBox<P>) };
+    // SAFETY: `this.ptr` came from a previous call to `P::into_foreign` in `Rcu::replace`, and
+    // reclamation only happens here, exactly once, after the grace period.
+    unsafe { drop(P::from_foreign(this.ptr)) };
+}
+
+/// An RCU-protected cell holding a published, atomically-swappable [`ForeignOwnable`] pointer.
+///
+/// Readers call [`Self::dereference`] while holding an [`RcuReadGuard`] to get a
+/// [`ForeignOwnable::Borrowed`] tied to the guard's lifetime, with no locking on the read side.
+/// Updaters call [`Self::replace`], which publishes the new pointer with `rcu_assign_pointer` and
+/// defers freeing the old value to `call_rcu`, since readers may still be dereferencing it.
+///
+/// Concurrent writers must still serialize their calls to [`Self::replace`] with each other (e.g.
+/// via an external lock); only the read side is lock-free.
+///
+/// # Examples
+///
+/// ```
+/// # use kernel::types::{Rcu, RcuReadGuard};
+/// let cell = Rcu::new(Box::try_new(1)?);
+///
+/// {
+///     let guard = RcuReadGuard::new();
+///     assert_eq!(*cell.dereference(&guard), 1);
+/// }
+///
+/// // `replace` publishes a new value and defers reclaiming the old one until the grace period
+/// // that follows ends.
+/// cell.replace(Box::try_new(2)?);
+///
+/// {
+///     let guard = RcuReadGuard::new();
+///     assert_eq!(*cell.dereference(&guard), 2);
+/// }
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// # Invariants
+///
+/// The pointer stored in `ptr` is either null or was produced by a previous call to
+/// `P::into_foreign` that has not yet been reclaimed, and it is only ever written through
+/// `rcu_assign_pointer`.
+pub struct Rcu<P: ForeignOwnable> {
+    ptr: Opaque<*mut core::ffi::c_void>,
+    _p: PhantomData<P>,
+}
+
+// SAFETY: `Rcu<P>` only ever stores and hands out what `P` itself would allow to cross threads.
+unsafe impl<P: ForeignOwnable + Send> Send for Rcu<P> {}
+// SAFETY: See above; reads go through `P::borrow`, exactly as sharing a `&P` would.
+unsafe impl<P: ForeignOwnable + Sync> Sync for Rcu<P> {}
+
+impl<P: ForeignOwnable> Rcu<P> {
+    /// Constructs a new [`Rcu`] cell, initially publishing `initial`.
+    pub fn new(initial: P) -> Self {
+        Self {
+            ptr: Opaque::new(initial.into_foreign() as *mut _),
+            _p: PhantomData,
+        }
+    }
+
+    /// Dereferences the currently-published pointer.
+    ///
+    /// `guard` is a proof that the caller is inside an RCU read-side critical section; the
+    /// returned borrow must not outlive it.
+    pub fn dereference<'a>(&'a self, guard: &'a RcuReadGuard) -> P::Borrowed<'a> {
+        let _ = guard;
+        // SAFETY: `self.ptr.get()` is valid for the lifetime of `self`, and it is only ever
+        // written through `rust_helper_rcu_assign_pointer` in `replace`.
+        let raw = unsafe { rcu_dereference(self.ptr.get()) };
+        // SAFETY: `raw` came from a previous `P::into_foreign`, and `guard` proves we are inside a
+        // read-side critical section for all of `'a`; any concurrent `replace` defers freeing the
+        // old pointer via `call_rcu` until after this grace period ends.
+        unsafe { P::borrow(raw as *const core::ffi::c_void) }
+    }
+
+    /// Publishes `new`, scheduling deferred reclamation of the previously-published value.
+    ///
+    /// Callers must serialize calls to `replace` with each other.
+    pub fn replace(&self, new: P) {
+        let new_ptr = new.into_foreign() as *mut core::ffi::c_void;
+        // SAFETY: `self.ptr.get()` is valid, and publishing through `rcu_assign_pointer` gives
+        // concurrent readers the ordering guarantee that every write `new` made before this call
+        // is visible once they observe the new pointer.
+        let old_ptr = unsafe {
+            let old = core::ptr::read(self.ptr.get());
+            rust_helper_rcu_assign_pointer(self.ptr.get(), new_ptr);
+            old
+        };
+
+        if old_ptr.is_null() {
+            return;
+        }
+
+        let rcu_box = Box::new(RcuBox::<P> {
+            head: bindings::callback_head::default(),
+            ptr: old_ptr,
+            _p: PhantomData,
+        });
+        let raw = Box::into_raw(rcu_box);
+        // SAFETY: `raw` was just obtained from `Box::into_raw`, so it is valid to take the
+        // address of its `head` field and hand it to `call_rcu`; `rcu_reclaim::<P>` reclaims the
+        // box (never synchronously) only after the grace period, matching the safety
+        // requirements of `call_rcu` given readers may still hold the old pointer.
+        unsafe {
+            rust_helper_call_rcu(core::ptr::addr_of_mut!((*raw).head), rcu_reclaim::<P>);
+        }
+    }
+}
+
+impl<P: ForeignOwnable> Drop for Rcu<P> {
+    fn drop(&mut self) {
+        // SAFETY: `self.ptr.get()` is valid, and nothing else can be reading `self` now that it
+        // is being dropped, so reclaiming synchronously (rather than via `call_rcu`) is sound.
+        let ptr = unsafe { core::ptr::read(self.ptr.get()) };
+        if !ptr.is_null() {
+            // SAFETY: `ptr` came from a previous `P::into_foreign` and has not been reclaimed.
+            unsafe { drop(P::from_foreign(ptr)) };
+        }
+    }
+}
+
 /// A wrapper for [`sched_param`].
 #[derive(Default)]
 #[repr(transparent)]
 pub struct SchedParam {
-    #[allow(dead_code)]
     sched_param: bindings::sched_param,
 }
 
@@ -817,73 +1591,600 @@ impl SchedParam {
             sched_param: bindings::sched_param { sched_priority: n },
         }
     }
+
+    /// Applies `policy` and this priority to `task` via `sched_setscheduler`.
+    ///
+    /// # Safety
+    ///
+    /// `task` must point at a valid `task_struct`.
+    pub unsafe fn apply_to(&self, task: *mut bindings::task_struct, policy: SchedPolicy) -> Result {
+        extern "C" {
+            fn rust_helper_sched_setscheduler(
+                task: *mut bindings::task_struct,
+                policy: c_types::c_int,
+                param: *const bindings::sched_param,
+            ) -> c_types::c_int;
+        }
+        // SAFETY: `task` is a valid task pointer per the caller's safety obligations, and
+        // `&self.sched_param` is valid for the duration of the call.
+        to_result(unsafe {
+            rust_helper_sched_setscheduler(task, policy.as_raw(), &self.sched_param)
+        })
+    }
+}
+
+/// The scheduling policies a task can be placed under.
+///
+/// Since RROS is a real-time variant, [`SchedParam`] alone only covers the static-priority
+/// policies; [`SchedPolicy::Deadline`] additionally needs a [`SchedAttr`] to carry its
+/// runtime/deadline/period triple.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SchedPolicy {
+    /// `SCHED_NORMAL`: the default, non-real-time policy.
+    Normal,
+    /// `SCHED_FIFO`: a static-priority first-in-first-out real-time policy.
+    Fifo,
+    /// `SCHED_RR`: a static-priority round-robin real-time policy.
+    RoundRobin,
+    /// `SCHED_DEADLINE`: the earliest-deadline-first real-time policy.
+    Deadline,
+}
+
+impl SchedPolicy {
+    fn as_raw(self) -> c_types::c_int {
+        match self {
+            Self::Normal => bindings::SCHED_NORMAL as _,
+            Self::Fifo => bindings::SCHED_FIFO as _,
+            Self::RoundRobin => bindings::SCHED_RR as _,
+            Self::Deadline => bindings::SCHED_DEADLINE as _,
+        }
+    }
+
+    fn from_raw(policy: c_types::c_int) -> Option<Self> {
+        let policy = policy as _;
+        if policy == bindings::SCHED_NORMAL {
+            Some(Self::Normal)
+        } else if policy == bindings::SCHED_FIFO {
+            Some(Self::Fifo)
+        } else if policy == bindings::SCHED_RR {
+            Some(Self::RoundRobin)
+        } else if policy == bindings::SCHED_DEADLINE {
+            Some(Self::Deadline)
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the scheduling policy currently applied to `task`, if it is one [`SchedPolicy`] knows
+/// about.
+///
+/// # Safety
+///
+/// `task` must point at a valid `task_struct`.
+pub unsafe fn sched_getscheduler(task: *mut bindings::task_struct) -> Option<SchedPolicy> {
+    extern "C" {
+        fn rust_helper_sched_getscheduler(task: *mut bindings::task_struct) -> c_types::c_int;
+    }
+    // SAFETY: `task` is a valid task pointer per the caller's safety obligations.
+    SchedPolicy::from_raw(unsafe { rust_helper_sched_getscheduler(task) })
 }
 
-/// A wrapper for [`atomic_t`].
+/// Returns the static real-time priority currently applied to `task`.
+///
+/// # Safety
+///
+/// `task` must point at a valid `task_struct`.
+pub unsafe fn sched_getparam(task: *mut bindings::task_struct) -> c_types::c_int {
+    extern "C" {
+        fn rust_helper_sched_getparam(
+            task: *mut bindings::task_struct,
+            param: *mut bindings::sched_param,
+        ) -> c_types::c_int;
+    }
+    let mut param = bindings::sched_param::default();
+    // SAFETY: `task` is a valid task pointer per the caller's safety obligations, and `&mut
+    // param` is valid for the duration of the call.
+    unsafe { rust_helper_sched_getparam(task, &mut param) };
+    param.sched_priority
+}
+
+/// A wrapper for [`sched_attr`], used to configure `SCHED_DEADLINE`'s runtime/deadline/period
+/// triple, all in nanoseconds.
 #[repr(transparent)]
-pub struct Atomic(bindings::atomic_t);
+pub struct SchedAttr {
+    attr: bindings::sched_attr,
+}
 
-impl Atomic {
-    /// Constructs a new struct.
-    pub fn new() -> Self {
-        Atomic(bindings::atomic_t::default())
+impl SchedAttr {
+    /// Constructs a `SCHED_DEADLINE` attribute set with the given runtime/deadline/period triple,
+    /// all in nanoseconds.
+    pub fn new_deadline(runtime: u64, deadline: u64, period: u64) -> Self {
+        let mut attr = bindings::sched_attr::default();
+        attr.size = core::mem::size_of::<bindings::sched_attr>() as _;
+        attr.sched_policy = SchedPolicy::Deadline.as_raw() as _;
+        attr.sched_runtime = runtime;
+        attr.sched_deadline = deadline;
+        attr.sched_period = period;
+        Self { attr }
+    }
+
+    /// Applies this attribute set to `task` via `sched_setattr`.
+    ///
+    /// # Safety
+    ///
+    /// `task` must point at a valid `task_struct`.
+    pub unsafe fn apply_to(&self, task: *mut bindings::task_struct) -> Result {
+        extern "C" {
+            fn rust_helper_sched_setattr(
+                task: *mut bindings::task_struct,
+                attr: *mut bindings::sched_attr,
+            ) -> c_types::c_int;
+        }
+        let mut attr = self.attr;
+        // SAFETY: `task` is a valid task pointer per the caller's safety obligations, and `&mut
+        // attr` is valid for the duration of the call.
+        to_result(unsafe { rust_helper_sched_setattr(task, &mut attr) })
     }
+}
+
+/// Memory ordering for [`Atomic`] operations.
+///
+/// Mirrors the orderings the C atomic helpers support: the unsuffixed helper (a full barrier) and
+/// the `_relaxed`/`_acquire`/`_release` variants. The kernel's atomics don't have a helper that is
+/// "acquire and release but not a full barrier", so [`Ordering::AcqRel`] is mapped onto the same
+/// full-barrier helper as [`Ordering::SeqCst`].
+///
+/// `load` and `store` have no full-barrier helper: with [`Ordering::AcqRel`] or
+/// [`Ordering::SeqCst`] they use the `_acquire` read and `_release` set helpers, and a load with
+/// [`Ordering::Release`] or a store with [`Ordering::Acquire`] uses the relaxed helper.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Ordering {
+    /// No ordering constraint beyond the atomicity of the operation itself.
+    Relaxed,
+    /// Synchronizes-with a matching [`Ordering::Release`] (or stronger) operation on the same
+    /// location.
+    Acquire,
+    /// Synchronizes-with a matching [`Ordering::Acquire`] (or stronger) operation on the same
+    /// location.
+    Release,
+    /// Both an [`Ordering::Acquire`] and a [`Ordering::Release`].
+    AcqRel,
+    /// A full memory barrier for read-modify-write operations; see above for `load` and `store`.
+    SeqCst,
+}
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for i32 {}
+    impl Sealed for i64 {}
+}
 
-    /// Add a num to self.
-    pub fn atomic_add(&mut self, i: i32) {
+/// Integer widths usable with [`Atomic`]: 32-bit (backed by `atomic_t`) and 64-bit (backed by
+/// `atomic64_t`).
+///
+/// # Safety
+///
+/// Implementers must forward every method to the correspondingly-ordered `rust_helper_atomic*`
+/// helper for their backing C type, and `Repr` must have the same layout as that C type.
+pub unsafe trait AtomicType: private::Sealed + Copy {
+    #[doc(hidden)]
+    type Repr: Copy;
+    #[doc(hidden)]
+    fn zero_repr() -> Self::Repr;
+    #[doc(hidden)]
+    unsafe fn load(v: *mut Self::Repr, ord: Ordering) -> Self;
+    #[doc(hidden)]
+    unsafe fn store(v: *mut Self::Repr, val: Self, ord: Ordering);
+    #[doc(hidden)]
+    unsafe fn fetch_add(v: *mut Self::Repr, val: Self, ord: Ordering) -> Self;
+    #[doc(hidden)]
+    unsafe fn fetch_sub(v: *mut Self::Repr, val: Self, ord: Ordering) -> Self;
+    #[doc(hidden)]
+    unsafe fn fetch_or(v: *mut Self::Repr, val: Self, ord: Ordering) -> Self;
+    #[doc(hidden)]
+    unsafe fn fetch_and(v: *mut Self::Repr, val: Self, ord: Ordering) -> Self;
+    #[doc(hidden)]
+    unsafe fn fetch_xor(v: *mut Self::Repr, val: Self, ord: Ordering) -> Self;
+    #[doc(hidden)]
+    unsafe fn xchg(v: *mut Self::Repr, val: Self, ord: Ordering) -> Self;
+    #[doc(hidden)]
+    unsafe fn compare_exchange(
+        v: *mut Self::Repr,
+        old: Self,
+        new: Self,
+        ord: Ordering,
+    ) -> Result<Self, Self>;
+}
+
+unsafe impl AtomicType for i32 {
+    type Repr = bindings::atomic_t;
+
+    fn zero_repr() -> Self::Repr {
+        bindings::atomic_t::default()
+    }
+
+    unsafe fn load(v: *mut Self::Repr, ord: Ordering) -> Self {
+        extern "C" {
+            fn rust_helper_atomic_read(v: *mut bindings::atomic_t) -> i32;
+            fn rust_helper_atomic_read_acquire(v: *mut bindings::atomic_t) -> i32;
+        }
         unsafe {
-            rust_helper_atomic_add(i, &mut self.0 as *mut bindings::atomic_t);
+            match ord {
+                Ordering::Acquire | Ordering::AcqRel | Ordering::SeqCst => {
+                    rust_helper_atomic_read_acquire(v)
+                }
+                Ordering::Relaxed | Ordering::Release => rust_helper_atomic_read(v),
+            }
         }
     }
 
-    /// Subtract a num to self.
-    pub fn atomic_sub(&mut self, i: i32) {
+    unsafe fn store(v: *mut Self::Repr, val: Self, ord: Ordering) {
+        extern "C" {
+            fn rust_helper_atomic_set(v: *mut bindings::atomic_t, i: i32);
+            fn rust_helper_atomic_set_release(v: *mut bindings::atomic_t, i: i32);
+        }
         unsafe {
-            rust_helper_atomic_sub(i, &mut self.0 as *mut bindings::atomic_t);
+            match ord {
+                Ordering::Release | Ordering::AcqRel | Ordering::SeqCst => {
+                    rust_helper_atomic_set_release(v, val)
+                }
+                Ordering::Relaxed | Ordering::Acquire => rust_helper_atomic_set(v, val),
+            }
         }
     }
 
-    /// Subtract and return the old value.
-    pub fn atomic_sub_return(&mut self, i: i32) -> i32 {
-        unsafe { rust_helper_atomic_sub_return(i, &mut self.0 as *mut bindings::atomic_t) }
+    unsafe fn fetch_add(v: *mut Self::Repr, val: Self, ord: Ordering) -> Self {
+        extern "C" {
+            fn rust_helper_atomic_fetch_add_relaxed(i: i32, v: *mut bindings::atomic_t) -> i32;
+            fn rust_helper_atomic_fetch_add_acquire(i: i32, v: *mut bindings::atomic_t) -> i32;
+            fn rust_helper_atomic_fetch_add_release(i: i32, v: *mut bindings::atomic_t) -> i32;
+            fn rust_helper_atomic_fetch_add(i: i32, v: *mut bindings::atomic_t) -> i32;
+        }
+        unsafe {
+            match ord {
+                Ordering::Relaxed => rust_helper_atomic_fetch_add_relaxed(val, v),
+                Ordering::Acquire => rust_helper_atomic_fetch_add_acquire(val, v),
+                Ordering::Release => rust_helper_atomic_fetch_add_release(val, v),
+                Ordering::AcqRel | Ordering::SeqCst => rust_helper_atomic_fetch_add(val, v),
+            }
+        }
     }
 
-    /// Add to self and return the old value.
-    pub fn atomic_add_return(&mut self, i: i32) -> i32 {
-        unsafe { rust_helper_atomic_add_return(i, &mut self.0 as *mut bindings::atomic_t) }
+    unsafe fn fetch_sub(v: *mut Self::Repr, val: Self, ord: Ordering) -> Self {
+        // SAFETY: Subtracting `val` is adding its negation; the C helpers have no distinct
+        // "fetch_sub" family, so the crate forwards through `fetch_add` the same way the kernel's
+        // own arch fallbacks do.
+        unsafe { Self::fetch_add(v, val.wrapping_neg(), ord) }
     }
 
-    /// Compare, if same exchange to new, else nothing to do.
-    pub fn atomic_cmpxchg(&mut self, old: i32, new: i32) -> i32 {
-        unsafe { rust_helper_atomic_cmpxchg(&mut self.0 as *mut bindings::atomic_t, old, new) }
+    unsafe fn fetch_or(v: *mut Self::Repr, val: Self, ord: Ordering) -> Self {
+        extern "C" {
+            fn rust_helper_atomic_fetch_or_relaxed(i: i32, v: *mut bindings::atomic_t) -> i32;
+            fn rust_helper_atomic_fetch_or_acquire(i: i32, v: *mut bindings::atomic_t) -> i32;
+            fn rust_helper_atomic_fetch_or_release(i: i32, v: *mut bindings::atomic_t) -> i32;
+            fn rust_helper_atomic_fetch_or(i: i32, v: *mut bindings::atomic_t) -> i32;
+        }
+        unsafe {
+            match ord {
+                Ordering::Relaxed => rust_helper_atomic_fetch_or_relaxed(val, v),
+                Ordering::Acquire => rust_helper_atomic_fetch_or_acquire(val, v),
+                Ordering::Release => rust_helper_atomic_fetch_or_release(val, v),
+                Ordering::AcqRel | Ordering::SeqCst => rust_helper_atomic_fetch_or(val, v),
+            }
+        }
     }
 
-    /// Set to a num.
-    pub fn atomic_set(&mut self, i: i32) {
+    unsafe fn fetch_and(v: *mut Self::Repr, val: Self, ord: Ordering) -> Self {
+        extern "C" {
+            fn rust_helper_atomic_fetch_and_relaxed(i: i32, v: *mut bindings::atomic_t) -> i32;
+            fn rust_helper_atomic_fetch_and_acquire(i: i32, v: *mut bindings::atomic_t) -> i32;
+            fn rust_helper_atomic_fetch_and_release(i: i32, v: *mut bindings::atomic_t) -> i32;
+            fn rust_helper_atomic_fetch_and(i: i32, v: *mut bindings::atomic_t) -> i32;
+        }
         unsafe {
-            rust_helper_atomic_set(&mut self.0 as *mut bindings::atomic_t, i);
+            match ord {
+                Ordering::Relaxed => rust_helper_atomic_fetch_and_relaxed(val, v),
+                Ordering::Acquire => rust_helper_atomic_fetch_and_acquire(val, v),
+                Ordering::Release => rust_helper_atomic_fetch_and_release(val, v),
+                Ordering::AcqRel | Ordering::SeqCst => rust_helper_atomic_fetch_and(val, v),
+            }
         }
     }
 
-    /// Plus one.
-    pub fn atomic_inc(&mut self) {
+    unsafe fn fetch_xor(v: *mut Self::Repr, val: Self, ord: Ordering) -> Self {
+        extern "C" {
+            fn rust_helper_atomic_fetch_xor_relaxed(i: i32, v: *mut bindings::atomic_t) -> i32;
+            fn rust_helper_atomic_fetch_xor_acquire(i: i32, v: *mut bindings::atomic_t) -> i32;
+            fn rust_helper_atomic_fetch_xor_release(i: i32, v: *mut bindings::atomic_t) -> i32;
+            fn rust_helper_atomic_fetch_xor(i: i32, v: *mut bindings::atomic_t) -> i32;
+        }
         unsafe {
-            rust_helper_atomic_inc(&mut self.0 as *mut bindings::atomic_t);
+            match ord {
+                Ordering::Relaxed => rust_helper_atomic_fetch_xor_relaxed(val, v),
+                Ordering::Acquire => rust_helper_atomic_fetch_xor_acquire(val, v),
+                Ordering::Release => rust_helper_atomic_fetch_xor_release(val, v),
+                Ordering::AcqRel | Ordering::SeqCst => rust_helper_atomic_fetch_xor(val, v),
+            }
+        }
+    }
+
+    unsafe fn xchg(v: *mut Self::Repr, val: Self, ord: Ordering) -> Self {
+        extern "C" {
+            fn rust_helper_atomic_xchg_relaxed(v: *mut bindings::atomic_t, i: i32) -> i32;
+            fn rust_helper_atomic_xchg_acquire(v: *mut bindings::atomic_t, i: i32) -> i32;
+            fn rust_helper_atomic_xchg_release(v: *mut bindings::atomic_t, i: i32) -> i32;
+            fn rust_helper_atomic_xchg(v: *mut bindings::atomic_t, i: i32) -> i32;
+        }
+        unsafe {
+            match ord {
+                Ordering::Relaxed => rust_helper_atomic_xchg_relaxed(v, val),
+                Ordering::Acquire => rust_helper_atomic_xchg_acquire(v, val),
+                Ordering::Release => rust_helper_atomic_xchg_release(v, val),
+                Ordering::AcqRel | Ordering::SeqCst => rust_helper_atomic_xchg(v, val),
+            }
+        }
+    }
+
+    unsafe fn compare_exchange(
+        v: *mut Self::Repr,
+        old: Self,
+        new: Self,
+        ord: Ordering,
+    ) -> Result<Self, Self> {
+        extern "C" {
+            fn rust_helper_atomic_cmpxchg_relaxed(v: *mut bindings::atomic_t, old: i32, new: i32) -> i32;
+            fn rust_helper_atomic_cmpxchg_acquire(v: *mut bindings::atomic_t, old: i32, new: i32) -> i32;
+            fn rust_helper_atomic_cmpxchg_release(v: *mut bindings::atomic_t, old: i32, new: i32) -> i32;
+            fn rust_helper_atomic_cmpxchg(v: *mut bindings::atomic_t, old: i32, new: i32) -> i32;
+        }
+        let observed = unsafe {
+            match ord {
+                Ordering::Relaxed => rust_helper_atomic_cmpxchg_relaxed(v, old, new),
+                Ordering::Acquire => rust_helper_atomic_cmpxchg_acquire(v, old, new),
+                Ordering::Release => rust_helper_atomic_cmpxchg_release(v, old, new),
+                Ordering::AcqRel | Ordering::SeqCst => rust_helper_atomic_cmpxchg(v, old, new),
+            }
+        };
+        if observed == old {
+            Ok(observed)
+        } else {
+            Err(observed)
         }
     }
+}
 
-    /// Sub one and test whether is zero.
-    pub fn atomic_dec_and_test(&mut self) -> bool {
-        unsafe { rust_helper_atomic_dec_and_test(&mut self.0 as *mut bindings::atomic_t) }
+unsafe impl AtomicType for i64 {
+    type Repr = bindings::atomic64_t;
+
+    fn zero_repr() -> Self::Repr {
+        bindings::atomic64_t::default()
     }
 
-    /// Sub one and return the old value.
-    pub fn atomic_dec_return(&mut self) -> i32 {
-        unsafe { rust_helper_atomic_dec_return(&mut self.0 as *mut bindings::atomic_t) }
+    unsafe fn load(v: *mut Self::Repr, ord: Ordering) -> Self {
+        extern "C" {
+            fn rust_helper_atomic64_read(v: *mut bindings::atomic64_t) -> i64;
+            fn rust_helper_atomic64_read_acquire(v: *mut bindings::atomic64_t) -> i64;
+        }
+        unsafe {
+            match ord {
+                Ordering::Acquire | Ordering::AcqRel | Ordering::SeqCst => {
+                    rust_helper_atomic64_read_acquire(v)
+                }
+                Ordering::Relaxed | Ordering::Release => rust_helper_atomic64_read(v),
+            }
+        }
+    }
+
+    unsafe fn store(v: *mut Self::Repr, val: Self, ord: Ordering) {
+        extern "C" {
+            fn rust_helper_atomic64_set(v: *mut bindings::atomic64_t, i: i64);
+            fn rust_helper_atomic64_set_release(v: *mut bindings::atomic64_t, i: i64);
+        }
+        unsafe {
+            match ord {
+                Ordering::Release | Ordering::AcqRel | Ordering::SeqCst => {
+                    rust_helper_atomic64_set_release(v, val)
+                }
+                Ordering::Relaxed | Ordering::Acquire => rust_helper_atomic64_set(v, val),
+            }
+        }
     }
 
-    /// Read self's value.
-    pub fn atomic_read(&mut self) -> i32 {
-        unsafe { rust_helper_atomic_read(&mut self.0 as *mut bindings::atomic_t) }
+    unsafe fn fetch_add(v: *mut Self::Repr, val: Self, ord: Ordering) -> Self {
+        extern "C" {
+            fn rust_helper_atomic64_fetch_add_relaxed(i: i64, v: *mut bindings::atomic64_t) -> i64;
+            fn rust_helper_atomic64_fetch_add_acquire(i: i64, v: *mut bindings::atomic64_t) -> i64;
+            fn rust_helper_atomic64_fetch_add_release(i: i64, v: *mut bindings::atomic64_t) -> i64;
+            fn rust_helper_atomic64_fetch_add(i: i64, v: *mut bindings::atomic64_t) -> i64;
+        }
+        unsafe {
+            match ord {
+                Ordering::Relaxed => rust_helper_atomic64_fetch_add_relaxed(val, v),
+                Ordering::Acquire => rust_helper_atomic64_fetch_add_acquire(val, v),
+                Ordering::Release => rust_helper_atomic64_fetch_add_release(val, v),
+                Ordering::AcqRel | Ordering::SeqCst => rust_helper_atomic64_fetch_add(val, v),
+            }
+        }
+    }
+
+    unsafe fn fetch_sub(v: *mut Self::Repr, val: Self, ord: Ordering) -> Self {
+        // SAFETY: Same reasoning as the `i32` impl above.
+        unsafe { Self::fetch_add(v, val.wrapping_neg(), ord) }
+    }
+
+    unsafe fn fetch_or(v: *mut Self::Repr, val: Self, ord: Ordering) -> Self {
+        extern "C" {
+            fn rust_helper_atomic64_fetch_or_relaxed(i: i64, v: *mut bindings::atomic64_t) -> i64;
+            fn rust_helper_atomic64_fetch_or_acquire(i: i64, v: *mut bindings::atomic64_t) -> i64;
+            fn rust_helper_atomic64_fetch_or_release(i: i64, v: *mut bindings::atomic64_t) -> i64;
+            fn rust_helper_atomic64_fetch_or(i: i64, v: *mut bindings::atomic64_t) -> i64;
+        }
+        unsafe {
+            match ord {
+                Ordering::Relaxed => rust_helper_atomic64_fetch_or_relaxed(val, v),
+                Ordering::Acquire => rust_helper_atomic64_fetch_or_acquire(val, v),
+                Ordering::Release => rust_helper_atomic64_fetch_or_release(val, v),
+                Ordering::AcqRel | Ordering::SeqCst => rust_helper_atomic64_fetch_or(val, v),
+            }
+        }
+    }
+
+    unsafe fn fetch_and(v: *mut Self::Repr, val: Self, ord: Ordering) -> Self {
+        extern "C" {
+            fn rust_helper_atomic64_fetch_and_relaxed(i: i64, v: *mut bindings::atomic64_t) -> i64;
+            fn rust_helper_atomic64_fetch_and_acquire(i: i64, v: *mut bindings::atomic64_t) -> i64;
+            fn rust_helper_atomic64_fetch_and_release(i: i64, v: *mut bindings::atomic64_t) -> i64;
+            fn rust_helper_atomic64_fetch_and(i: i64, v: *mut bindings::atomic64_t) -> i64;
+        }
+        unsafe {
+            match ord {
+                Ordering::Relaxed => rust_helper_atomic64_fetch_and_relaxed(val, v),
+                Ordering::Acquire => rust_helper_atomic64_fetch_and_acquire(val, v),
+                Ordering::Release => rust_helper_atomic64_fetch_and_release(val, v),
+                Ordering::AcqRel | Ordering::SeqCst => rust_helper_atomic64_fetch_and(val, v),
+            }
+        }
+    }
+
+    unsafe fn fetch_xor(v: *mut Self::Repr, val: Self, ord: Ordering) -> Self {
+        extern "C" {
+            fn rust_helper_atomic64_fetch_xor_relaxed(i: i64, v: *mut bindings::atomic64_t) -> i64;
+            fn rust_helper_atomic64_fetch_xor_acquire(i: i64, v: *mut bindings::atomic64_t) -> i64;
+            fn rust_helper_atomic64_fetch_xor_release(i: i64, v: *mut bindings::atomic64_t) -> i64;
+            fn rust_helper_atomic64_fetch_xor(i: i64, v: *mut bindings::atomic64_t) -> i64;
+        }
+        unsafe {
+            match ord {
+                Ordering::Relaxed => rust_helper_atomic64_fetch_xor_relaxed(val, v),
+                Ordering::Acquire => rust_helper_atomic64_fetch_xor_acquire(val, v),
+                Ordering::Release => rust_helper_atomic64_fetch_xor_release(val, v),
+                Ordering::AcqRel | Ordering::SeqCst => rust_helper_atomic64_fetch_xor(val, v),
+            }
+        }
+    }
+
+    unsafe fn xchg(v: *mut Self::Repr, val: Self, ord: Ordering) -> Self {
+        extern "C" {
+            fn rust_helper_atomic64_xchg_relaxed(v: *mut bindings::atomic64_t, i: i64) -> i64;
+            fn rust_helper_atomic64_xchg_acquire(v: *mut bindings::atomic64_t, i: i64) -> i64;
+            fn rust_helper_atomic64_xchg_release(v: *mut bindings::atomic64_t, i: i64) -> i64;
+            fn rust_helper_atomic64_xchg(v: *mut bindings::atomic64_t, i: i64) -> i64;
+        }
+        unsafe {
+            match ord {
+                Ordering::Relaxed => rust_helper_atomic64_xchg_relaxed(v, val),
+                Ordering::Acquire => rust_helper_atomic64_xchg_acquire(v, val),
+                Ordering::Release => rust_helper_atomic64_xchg_release(v, val),
+                Ordering::AcqRel | Ordering::SeqCst => rust_helper_atomic64_xchg(v, val),
+            }
+        }
+    }
+
+    unsafe fn compare_exchange(
+        v: *mut Self::Repr,
+        old: Self,
+        new: Self,
+        ord: Ordering,
+    ) -> Result<Self, Self> {
+        extern "C" {
+            fn rust_helper_atomic64_cmpxchg_relaxed(v: *mut bindings::atomic64_t, old: i64, new: i64) -> i64;
+            fn rust_helper_atomic64_cmpxchg_acquire(v: *mut bindings::atomic64_t, old: i64, new: i64) -> i64;
+            fn rust_helper_atomic64_cmpxchg_release(v: *mut bindings::atomic64_t, old: i64, new: i64) -> i64;
+            fn rust_helper_atomic64_cmpxchg(v: *mut bindings::atomic64_t, old: i64, new: i64) -> i64;
+        }
+        let observed = unsafe {
+            match ord {
+                Ordering::Relaxed => rust_helper_atomic64_cmpxchg_relaxed(v, old, new),
+                Ordering::Acquire => rust_helper_atomic64_cmpxchg_acquire(v, old, new),
+                Ordering::Release => rust_helper_atomic64_cmpxchg_release(v, old, new),
+                Ordering::AcqRel | Ordering::SeqCst => rust_helper_atomic64_cmpxchg(v, old, new),
+            }
+        };
+        if observed == old {
+            Ok(observed)
+        } else {
+            Err(observed)
+        }
+    }
+}
+
+/// A generic, memory-ordering-aware atomic integer, backed by the C `atomic_t` (`T = i32`) or
+/// `atomic64_t` (`T = i64`).
+///
+/// Unlike the old `i32`-only wrapper this replaces, every method takes `&self`: the C helpers
+/// already provide whatever synchronisation the requested [`Ordering`] calls for, so there is
+/// nothing for Rust's `&mut` exclusivity to add.
+#[repr(transparent)]
+pub struct Atomic<T: AtomicType>(Opaque<T::Repr>);
+
+// SAFETY: All operations go through the C atomic helpers, which perform their own synchronisation,
+// so sharing a `&Atomic<T>` across threads is sound.
+unsafe impl<T: AtomicType> Sync for Atomic<T> {}
+
+impl<T: AtomicType> Atomic<T> {
+    /// Constructs a new atomic with initial value `v`.
+    pub fn new(v: T) -> Self {
+        let this = Self(Opaque::new(T::zero_repr()));
+        this.store(v, Ordering::Relaxed);
+        this
+    }
+
+    /// Loads the current value.
+    pub fn load(&self, ord: Ordering) -> T {
+        // SAFETY: `self.0.get()` is a valid pointer to the backing C atomic for its whole
+        // lifetime.
+        unsafe { T::load(self.0.get(), ord) }
+    }
+
+    /// Stores `val`.
+    pub fn store(&self, val: T, ord: Ordering) {
+        // SAFETY: Same as `load` above.
+        unsafe { T::store(self.0.get(), val, ord) }
+    }
+
+    /// Adds `val`, returning the previous value.
+    pub fn fetch_add(&self, val: T, ord: Ordering) -> T {
+        // SAFETY: Same as `load` above.
+        unsafe { T::fetch_add(self.0.get(), val, ord) }
+    }
+
+    /// Subtracts `val`, returning the previous value.
+    pub fn fetch_sub(&self, val: T, ord: Ordering) -> T {
+        // SAFETY: Same as `load` above.
+        unsafe { T::fetch_sub(self.0.get(), val, ord) }
+    }
+
+    /// Bitwise-ORs `val` into the value, returning the previous value.
+    pub fn fetch_or(&self, val: T, ord: Ordering) -> T {
+        // SAFETY: Same as `load` above.
+        unsafe { T::fetch_or(self.0.get(), val, ord) }
+    }
+
+    /// Bitwise-ANDs `val` into the value, returning the previous value.
+    pub fn fetch_and(&self, val: T, ord: Ordering) -> T {
+        // SAFETY: Same as `load` above.
+        unsafe { T::fetch_and(self.0.get(), val, ord) }
+    }
+
+    /// Bitwise-XORs `val` into the value, returning the previous value.
+    pub fn fetch_xor(&self, val: T, ord: Ordering) -> T {
+        // SAFETY: Same as `load` above.
+        unsafe { T::fetch_xor(self.0.get(), val, ord) }
+    }
+
+    /// Unconditionally stores `val`, returning the previous value.
+    pub fn xchg(&self, val: T, ord: Ordering) -> T {
+        // SAFETY: Same as `load` above.
+        unsafe { T::xchg(self.0.get(), val, ord) }
+    }
+
+    /// Stores `new` if the current value equals `old`.
+    ///
+    /// Returns `Ok(old)` if the exchange happened, otherwise `Err(observed)` with the value
+    /// actually found.
+    pub fn compare_exchange(&self, old: T, new: T, ord: Ordering) -> Result<T, T> {
+        // SAFETY: Same as `load` above.
+        unsafe { T::compare_exchange(self.0.get(), old, new, ord) }
     }
 }